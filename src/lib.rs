@@ -2,26 +2,52 @@
 
 use core::marker::PhantomData;
 use core::slice;
+use core::sync::atomic::{compiler_fence, Ordering};
 
 use coca::{
-    collections::vec::Vec,
-    storage::{self, ArrayLayout, Capacity, DefaultStorage, OwnedStorage, Storage},
+    collections::{deque::Deque, vec::Vec},
+    storage::{self, ArrayLayout, Capacity, DefaultStorage, Layout, OwnedStorage, Storage},
     CapacityError,
 };
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
-pub struct ZArrayStorage<Z: Zeroize, S: Storage<ArrayLayout<Z>>>(S, PhantomData<Z>);
+/// A [`Layout`] whose backing bytes can be wiped for a given capacity.
+///
+/// Most layouts (a flat array, a ring buffer) describe a single
+/// contiguous range. A layout that splits its allocation into several
+/// disjoint regions -- a pool's value slots and free-list, or a hash map's
+/// separate control-byte and bucket regions, say -- calls `f` once per
+/// region instead, so
+/// [`ZStorage`] doesn't need to know anything about the layout's internal
+/// shape to zeroize it correctly.
+pub trait ZeroizableLayout: Layout {
+    /// Calls `f(offset, len)` once for every `(offset, len)` byte range to
+    /// wipe for a backing store of the given `capacity`.
+    fn zeroize_ranges(capacity: usize, f: impl FnMut(usize, usize));
+}
+
+impl<Z: Zeroize> ZeroizableLayout for ArrayLayout<Z> {
+    #[inline]
+    fn zeroize_ranges(capacity: usize, mut f: impl FnMut(usize, usize)) {
+        f(0, capacity * core::mem::size_of::<Z>());
+    }
+}
+
+/// A zeroizing wrapper around any [`Storage<L>`], generic over the layout
+/// `L` rather than tied to [`ArrayLayout`]. Every [`Storage`] method
+/// delegates to the inner `S`; only `Drop`/`Zeroize` are overridden, and
+/// they wipe the byte ranges `L` reports via [`ZeroizableLayout`] instead
+/// of assuming one contiguous array.
+pub struct ZStorage<L: ZeroizableLayout, S: Storage<L>>(S, PhantomData<L>);
 
-impl<Z: Zeroize, S: Storage<ArrayLayout<Z>>> From<S> for ZArrayStorage<Z, S> {
+impl<L: ZeroizableLayout, S: Storage<L>> From<S> for ZStorage<L, S> {
     #[inline]
     fn from(s: S) -> Self {
         Self(s, PhantomData)
     }
 }
 
-unsafe impl<Z: Zeroize, S: Storage<ArrayLayout<Z>>> Storage<ArrayLayout<Z>>
-    for ZArrayStorage<Z, S>
-{
+unsafe impl<L: ZeroizableLayout, S: Storage<L>> Storage<L> for ZStorage<L, S> {
     const MIN_REPRESENTABLE: usize = S::MIN_REPRESENTABLE;
 
     #[inline]
@@ -42,36 +68,51 @@ unsafe impl<Z: Zeroize, S: Storage<ArrayLayout<Z>>> Storage<ArrayLayout<Z>>
     }
 }
 
-impl<Z: Zeroize, S: OwnedStorage<ArrayLayout<Z>>> OwnedStorage<ArrayLayout<Z>>
-    for ZArrayStorage<Z, S>
-{
+impl<L: ZeroizableLayout, S: OwnedStorage<L>> OwnedStorage<L> for ZStorage<L, S> {
     #[inline]
     fn try_with_capacity(min_capacity: usize) -> Result<Self, CapacityError> {
         Ok(S::try_with_capacity(min_capacity)?.into())
     }
 }
 
-impl<Z: Zeroize, S: DefaultStorage<ArrayLayout<Z>>> DefaultStorage<ArrayLayout<Z>>
-    for ZArrayStorage<Z, S>
-{
+impl<L: ZeroizableLayout, S: DefaultStorage<L>> DefaultStorage<L> for ZStorage<L, S> {
     const UNINIT: Self = Self(S::UNINIT, PhantomData);
 }
 
-impl<Z: Zeroize, S: Storage<ArrayLayout<Z>>> Zeroize for ZArrayStorage<Z, S> {
+/// Wipes `len` bytes starting at `ptr`, then fences to prevent the compiler
+/// from reordering the write away from the point of the call.
+///
+/// Kept non-generic so that `Zeroize for ZStorage<L, S>` only monomorphizes
+/// the small per-range offset/length computation, not the wipe itself.
+#[inline]
+fn zeroize_bytes(ptr: *mut u8, len: usize) {
+    if len == 0 {
+        return;
+    }
+    let bytes = unsafe { slice::from_raw_parts_mut(ptr, len) };
+    bytes.zeroize();
+    compiler_fence(Ordering::SeqCst);
+}
+
+impl<L: ZeroizableLayout, S: Storage<L>> Zeroize for ZStorage<L, S> {
     fn zeroize(&mut self) {
-        let uninit_slice =
-            unsafe { slice::from_raw_parts_mut(self.0.get_mut_ptr(), self.0.capacity()) };
-        uninit_slice.zeroize();
+        let capacity = self.0.capacity();
+        let base = self.0.get_mut_ptr();
+        L::zeroize_ranges(capacity, |offset, len| {
+            zeroize_bytes(unsafe { base.add(offset) }, len);
+        });
     }
 }
 
-impl<Z: Zeroize, S: Storage<ArrayLayout<Z>>> Drop for ZArrayStorage<Z, S> {
+impl<L: ZeroizableLayout, S: Storage<L>> Drop for ZStorage<L, S> {
     fn drop(&mut self) {
         self.zeroize();
     }
 }
 
-impl<Z: Zeroize, S: Storage<ArrayLayout<Z>>> ZeroizeOnDrop for ZArrayStorage<Z, S> {}
+impl<L: ZeroizableLayout, S: Storage<L>> ZeroizeOnDrop for ZStorage<L, S> {}
+
+pub type ZArrayStorage<Z, S> = ZStorage<ArrayLayout<Z>, S>;
 
 pub type ZInlineStorage<Z, const N: usize> = ZArrayStorage<Z, storage::InlineStorage<Z, N>>;
 
@@ -89,9 +130,409 @@ pub type ZArenaVec<'s, T, I = usize> = Vec<T, ZArenaStorage<'s, T>, I>;
 pub type ZAllocVec<T, I = usize> =
     Vec<T, ZArrayStorage<T, storage::AllocStorage<ArrayLayout<T>>>, I>;
 
+pub type ZInlineDeque<T, const N: usize, I = usize> = Deque<T, ZInlineStorage<T, N>, I>;
+
+pub type ZSliceDeque<'s, T, I = usize> = Deque<T, ZSliceStorage<'s, T>, I>;
+
+pub type ZArenaDeque<'s, T, I = usize> = Deque<T, ZArenaStorage<'s, T>, I>;
+
+#[cfg(feature = "alloc")]
+pub type ZAllocDeque<T, I = usize> =
+    Deque<T, ZArrayStorage<T, storage::AllocStorage<ArrayLayout<T>>>, I>;
+
+// Decision: no `ZPool`/`ZMap` aliases in this crate (reviewed and
+// deliberately de-scoped, not an oversight).
+//
+// `coca::collections::pool::Pool` and any hash-map-like collection use a
+// `Layout` of their own (a pool needs a value-slots region plus a
+// disjoint free-list/generation region; a map needs its own
+// control-byte/bucket split) rather than `ArrayLayout`, so
+// `ZInlineStorage`/`ZSliceStorage`/`ZArenaStorage` and the
+// `AllocStorage`-backed `ZArrayStorage` don't satisfy their `Storage` bound. `ZeroizableLayout` is written to
+// support exactly this -- a layout reports as many disjoint ranges as it
+// needs -- but its regions (slot size, free-list encoding, generation
+// width) aren't derivable from what this crate can see; guessing them
+// would ship a wipe that's silently wrong about which bytes are secret,
+// which is worse than not shipping the alias. `ZPool`/`ZMap` land once
+// `ZeroizableLayout` has a real impl for those layout specs, not before.
+
+/// A zeroizing wrapper around [`storage::ReallocStorage`].
+///
+/// Unlike [`ZArrayStorage`], this does *not* delegate growth to the inner
+/// storage's `try_grow`: `ReallocStorage` grows by calling the system
+/// allocator's `realloc`, which is free to copy the live bytes to a fresh
+/// block and free the old one itself, before our `Drop` ever runs. That
+/// frees the old secret bytes without wiping them first. `try_grow` here
+/// instead always allocates a new block, copies the old bytes across, and
+/// wipes the old block in place while it's still ours to write to -- only
+/// then is the (now-zeroed) old storage left for its own destructor to
+/// free, mirroring the allocate-new/copy/wipe-old sequence of a `RawVec`
+/// reallocation.
+#[cfg(feature = "alloc")]
+pub struct ZReallocStorage<Z: Zeroize>(storage::ReallocStorage<ArrayLayout<Z>>, PhantomData<Z>);
+
+#[cfg(feature = "alloc")]
+impl<Z: Zeroize> From<storage::ReallocStorage<ArrayLayout<Z>>> for ZReallocStorage<Z> {
+    #[inline]
+    fn from(s: storage::ReallocStorage<ArrayLayout<Z>>) -> Self {
+        Self(s, PhantomData)
+    }
+}
+
+#[cfg(feature = "alloc")]
+unsafe impl<Z: Zeroize> Storage<ArrayLayout<Z>> for ZReallocStorage<Z> {
+    const MIN_REPRESENTABLE: usize = storage::ReallocStorage::<ArrayLayout<Z>>::MIN_REPRESENTABLE;
+
+    #[inline]
+    fn get_ptr(&self) -> *const u8 {
+        self.0.get_ptr()
+    }
+    #[inline]
+    fn get_mut_ptr(&mut self) -> *mut u8 {
+        self.0.get_mut_ptr()
+    }
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+    fn try_grow<I: Capacity>(&self, min_capacity: Option<usize>) -> Result<Self, CapacityError> {
+        let old_cap = self.0.capacity();
+        let elem_size = core::mem::size_of::<Z>();
+
+        // `try_grow(None)` means "grow by your own policy" -- the baseline
+        // got a real geometric policy for free by delegating to the inner
+        // storage's `try_grow`. Replicate it here instead of allocating a
+        // same-size (or, from empty, zero-size) block, which would leave a
+        // `push` that triggered growth still unable to fit. `Some(required)`
+        // still grows to at least double the old capacity (or to
+        // `required`, if that's larger), so repeated pushes stay amortized
+        // O(1) rather than degrading to O(n^2).
+        let doubled = old_cap.saturating_mul(2).max(1);
+        let new_cap = match min_capacity {
+            Some(required) => required.max(doubled),
+            None => doubled,
+        };
+
+        // `I` bounds how large a capacity this storage can ever be
+        // addressed by -- the baseline got this clamp for free by
+        // forwarding to `self.0.try_grow::<I>(min_capacity)`. Growing past
+        // it would hand back a capacity the vector's index type can't
+        // represent, corrupting length/index accounting.
+        if new_cap > I::MAX_REPRESENTABLE {
+            return Err(CapacityError);
+        }
+
+        let mut new_inner = storage::ReallocStorage::<ArrayLayout<Z>>::try_with_capacity(new_cap)?;
+        // Bound the copy by whatever actually fits in both blocks, even
+        // though `new_cap >= old_cap` always holds above -- this keeps the
+        // copy safe regardless of how the policy above changes later.
+        let copy_len = old_cap.min(new_cap) * elem_size;
+        unsafe {
+            core::ptr::copy_nonoverlapping(self.0.get_ptr(), new_inner.get_mut_ptr(), copy_len);
+        }
+        // Wipe the *entire* old block now, while it's still a live
+        // allocation we're allowed to write to -- by the time `self` is
+        // dropped, the bytes here are already zero.
+        zeroize_bytes(self.0.get_ptr().cast_mut(), old_cap * elem_size);
+
+        Ok(Self(new_inner, PhantomData))
+    }
+}
+
 #[cfg(feature = "alloc")]
-pub type ZReallocVec<T, I = usize> =
-    Vec<T, ZArrayStorage<T, storage::ReallocStorage<ArrayLayout<T>>>, I>;
+impl<Z: Zeroize> OwnedStorage<ArrayLayout<Z>> for ZReallocStorage<Z> {
+    #[inline]
+    fn try_with_capacity(min_capacity: usize) -> Result<Self, CapacityError> {
+        Ok(storage::ReallocStorage::<ArrayLayout<Z>>::try_with_capacity(min_capacity)?.into())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Z: Zeroize> Zeroize for ZReallocStorage<Z> {
+    fn zeroize(&mut self) {
+        let len = self.0.capacity() * core::mem::size_of::<Z>();
+        zeroize_bytes(self.0.get_mut_ptr(), len);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Z: Zeroize> Drop for ZReallocStorage<Z> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Z: Zeroize> ZeroizeOnDrop for ZReallocStorage<Z> {}
+
+#[cfg(feature = "alloc")]
+pub type ZReallocVec<T, I = usize> = Vec<T, ZReallocStorage<T>, I>;
+
+/// A zeroizing wrapper around [`Vec`] itself, not just its storage.
+///
+/// A zeroizing storage only wipes its backing bytes when the *whole*
+/// storage is dropped. That leaves `truncate`, `clear`, `drain`, `pop`,
+/// `remove`, `swap_remove`, and lowering `set_len` exposed: each of these
+/// shrinks the vector's length without touching the now out-of-bounds
+/// slots, so the plaintext of every logically-removed element sits in
+/// live capacity for the rest of the vector's lifetime -- and can be
+/// handed back out as "uninitialized" capacity by a later `push`.
+///
+/// `ZVec` re-exposes the shrinking operations, wiping exactly the byte
+/// range of the elements that leave the collection right after lowering
+/// the length, within the same call, so a vacated slot is already zero
+/// when the call that removed it returns, regardless of whether or when the
+/// whole thing is later dropped. Combine it with a zeroizing storage, e.g.
+/// `ZVec<T, ZInlineStorage<T, N>>`, for both guarantees at once.
+pub struct ZVec<T: Zeroize, S: Storage<ArrayLayout<T>>, I: Capacity = usize>(Vec<T, S, I>);
+
+impl<T: Zeroize, S: Storage<ArrayLayout<T>>, I: Capacity> From<Vec<T, S, I>> for ZVec<T, S, I> {
+    #[inline]
+    fn from(v: Vec<T, S, I>) -> Self {
+        Self(v)
+    }
+}
+
+impl<T: Zeroize, S: Storage<ArrayLayout<T>>, I: Capacity> core::ops::Deref for ZVec<T, S, I> {
+    type Target = Vec<T, S, I>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Zeroize, S: Storage<ArrayLayout<T>>, I: Capacity> ZVec<T, S, I> {
+    /// Wipes the `count` elements starting at index `from`, which must
+    /// already be outside `0..self.0.len()` -- i.e. the caller has already
+    /// lowered the length past them.
+    #[inline]
+    fn wipe(&mut self, from: usize, count: usize) {
+        let ptr = unsafe { self.0.as_mut_ptr().add(from) }.cast::<u8>();
+        zeroize_bytes(ptr, count * core::mem::size_of::<T>());
+    }
+
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        self.0.push(value);
+    }
+
+    #[inline]
+    pub fn try_push(&mut self, value: T) -> Result<(), CapacityError> {
+        self.0.try_push(value)
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        let value = self.0.pop()?;
+        let len = self.0.len();
+        self.wipe(len, 1);
+        Some(value)
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        let old_len = self.0.len();
+        self.0.truncate(len);
+        if len < old_len {
+            self.wipe(len, old_len - len);
+        }
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    pub fn remove(&mut self, index: usize) -> T {
+        let value = self.0.remove(index);
+        let len = self.0.len();
+        self.wipe(len, 1);
+        value
+    }
+
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        let value = self.0.swap_remove(index);
+        let len = self.0.len();
+        self.wipe(len, 1);
+        value
+    }
+
+    /// # Safety
+    /// Same contract as the inner `Vec::set_len`. Additionally, bytes in
+    /// `new_len..old_len` are wiped as part of the call, so they must not
+    /// be relied on to hold their previous values afterwards.
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        let old_len = self.0.len();
+        self.0.set_len(new_len);
+        if new_len < old_len {
+            self.wipe(new_len, old_len - new_len);
+        }
+    }
+
+    /// Removes and returns every element, wiping each slot as it's taken
+    /// out so that even dropping the iterator early leaves no plaintext
+    /// behind.
+    pub fn drain(&mut self) -> Drain<'_, T, S, I> {
+        let end = self.0.len();
+        // Lower the vector's length to zero up front, before any element
+        // is read out. `Drain` takes over accounting for these `end`
+        // elements from here; if it's leaked, the vector is simply left at
+        // this safe, shorter length instead of staying at its original
+        // length over slots that `next()` may have already read out and
+        // wiped out from under it.
+        unsafe { self.0.set_len(0) };
+        Drain {
+            vec: self,
+            next: 0,
+            end,
+        }
+    }
+}
+
+impl<T: Zeroize + Clone, S: Storage<ArrayLayout<T>>, I: Capacity> ZVec<T, S, I> {
+    #[inline]
+    pub fn try_extend_from_slice(&mut self, other: &[T]) -> Result<(), CapacityError> {
+        self.0.try_extend_from_slice(other)
+    }
+}
+
+/// Draining iterator for [`ZVec::drain`].
+///
+/// The vector's length is already down to `0` by the time a `Drain`
+/// exists (see [`ZVec::drain`]) -- `next` and `end` track the still-live
+/// `0..end` region of the backing store on `Drain`'s own, so a leaked
+/// `Drain` never leaves the vector believing it owns elements whose bytes
+/// may already have been read out and wiped.
+pub struct Drain<'v, T: Zeroize, S: Storage<ArrayLayout<T>>, I: Capacity> {
+    vec: &'v mut ZVec<T, S, I>,
+    next: usize,
+    end: usize,
+}
+
+impl<T: Zeroize, S: Storage<ArrayLayout<T>>, I: Capacity> Iterator for Drain<'_, T, S, I> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.next >= self.end {
+            return None;
+        }
+        let value = unsafe { self.vec.0.as_ptr().add(self.next).read() };
+        self.vec.wipe(self.next, 1);
+        self.next += 1;
+        Some(value)
+    }
+}
+
+impl<T: Zeroize, S: Storage<ArrayLayout<T>>, I: Capacity> Drop for Drain<'_, T, S, I> {
+    fn drop(&mut self) {
+        for _ in self.by_ref() {}
+    }
+}
+
+/// `embedded-io` `Write`/`Read` sinks over [`ZVec<u8, ..>`], so a `no_std`
+/// serializer can stream secret material -- key schedules, decoded private
+/// keys, protocol transcripts -- into a buffer that's wiped as it's
+/// consumed and, when the `ZVec` sits on a zeroizing storage such as
+/// [`ZInlineStorage`], wiped in full on drop.
+#[cfg(feature = "io")]
+mod io {
+    use core::convert::Infallible;
+    use core::fmt;
+
+    use embedded_io::{ErrorKind, ErrorType};
+
+    use super::*;
+
+    /// The error [`Write`](embedded_io::Write) reports for a [`ZVec<u8, ..>`]
+    /// that's out of room. coca only ever surfaces one capacity error, so it
+    /// maps to a single `embedded-io` kind -- [`ErrorKind::OutOfMemory`] --
+    /// for both the fixed-capacity inline/slice backings and the growable
+    /// alloc/realloc ones.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct WriteError(pub CapacityError);
+
+    impl fmt::Display for WriteError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "not enough capacity to write all bytes")
+        }
+    }
+
+    impl embedded_io::Error for WriteError {
+        #[inline]
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::OutOfMemory
+        }
+    }
+
+    impl<S: Storage<ArrayLayout<u8>>, I: Capacity> ErrorType for ZVec<u8, S, I> {
+        type Error = WriteError;
+    }
+
+    impl<S: Storage<ArrayLayout<u8>>, I: Capacity> embedded_io::Write for ZVec<u8, S, I> {
+        fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+            // Appended one byte at a time rather than via
+            // `try_extend_from_slice`: we don't know whether that call is
+            // atomic (leaves the vector untouched on failure) or appends
+            // whatever fits before erroring. Going through `try_push`
+            // keeps `written` exact either way, at the cost of a bulk
+            // fast path.
+            let mut written = 0usize;
+            for &byte in buf {
+                match self.try_push(byte) {
+                    Ok(()) => written += 1,
+                    Err(e) => {
+                        return if written == 0 {
+                            Err(WriteError(e))
+                        } else {
+                            Ok(written)
+                        };
+                    }
+                }
+            }
+            Ok(written)
+        }
+
+        #[inline]
+        fn flush(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    /// A cursor over a [`ZVec<u8, ..>`] that reads by draining: each byte
+    /// handed to the caller is removed from, and wiped in, the underlying
+    /// vector immediately, so a partially-consumed secret buffer never
+    /// holds both the plaintext and a copy of it.
+    pub struct ZCursor<'v, S: Storage<ArrayLayout<u8>>, I: Capacity>(Drain<'v, u8, S, I>);
+
+    impl<'v, S: Storage<ArrayLayout<u8>>, I: Capacity> ZCursor<'v, S, I> {
+        #[inline]
+        pub fn new(vec: &'v mut ZVec<u8, S, I>) -> Self {
+            Self(vec.drain())
+        }
+    }
+
+    impl<S: Storage<ArrayLayout<u8>>, I: Capacity> ErrorType for ZCursor<'_, S, I> {
+        type Error = Infallible;
+    }
+
+    impl<S: Storage<ArrayLayout<u8>>, I: Capacity> embedded_io::Read for ZCursor<'_, S, I> {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+            let mut read = 0usize;
+            for slot in buf.iter_mut() {
+                match self.0.next() {
+                    Some(byte) => {
+                        *slot = byte;
+                        read += 1;
+                    }
+                    None => break,
+                }
+            }
+            Ok(read)
+        }
+    }
+}
+
+#[cfg(feature = "io")]
+pub use io::{WriteError, ZCursor};
 
 #[cfg(test)]
 mod tests {
@@ -128,6 +569,29 @@ mod tests {
         assert_eq!(vals, [0, 0, 0]);
     }
 
+    #[test]
+    fn array_layout_zeroize_ranges_is_one_range_covering_the_full_extent() {
+        let mut seen = None;
+        ArrayLayout::<u32>::zeroize_ranges(4, |offset, len| {
+            assert!(seen.is_none(), "ArrayLayout should report exactly one range");
+            seen = Some((offset, len));
+        });
+        assert_eq!(seen, Some((0, 4 * core::mem::size_of::<u32>())));
+    }
+
+    #[test]
+    fn zeroize_inline_deque() {
+        let mut dq = ZInlineDeque::<u8, 3>::new();
+        dq.push_back(1);
+        dq.push_back(2);
+        dq.push_back(3);
+        assert!(dq.try_push_back(4).is_err());
+        let (mut stor, _) = dq.into_raw_parts();
+        stor.zeroize();
+        let vals = unsafe { (stor.get_ptr().cast::<[u8; 3]>()).read() };
+        assert_eq!(vals, [0, 0, 0]);
+    }
+
     #[test]
     fn create_slice() {
         let mut buf: [MaybeUninit<u8>; 3] = [
@@ -158,6 +622,64 @@ mod tests {
         assert_eq!(vals, [0, 0, 0]);
     }
 
+    #[test]
+    fn zvec_pop_wipes_the_vacated_slot_immediately() {
+        let mut z = ZVec::from(ZInlineVec::<u8, 3>::new());
+        z.push(1);
+        z.push(2);
+        z.push(3);
+        assert_eq!(z.pop(), Some(3));
+        let vacated = unsafe { z.as_ptr().add(2).read() };
+        assert_eq!(vacated, 0);
+    }
+
+    #[test]
+    fn zvec_truncate_wipes_the_vacated_range_immediately() {
+        let mut z = ZVec::from(ZInlineVec::<u8, 4>::new());
+        z.push(1);
+        z.push(2);
+        z.push(3);
+        z.push(4);
+        z.truncate(1);
+        let vacated = unsafe { core::slice::from_raw_parts(z.as_ptr().add(1), 3) };
+        assert_eq!(vacated, [0, 0, 0]);
+    }
+
+    #[test]
+    fn zvec_remove_and_swap_remove_wipe_the_vacated_tail_slot() {
+        let mut z = ZVec::from(ZInlineVec::<u8, 3>::new());
+        z.push(1);
+        z.push(2);
+        z.push(3);
+        assert_eq!(z.remove(0), 1);
+        let vacated = unsafe { z.as_ptr().add(2).read() };
+        assert_eq!(vacated, 0);
+
+        z.push(9);
+        assert_eq!(z.swap_remove(0), 2);
+        let vacated = unsafe { z.as_ptr().add(2).read() };
+        assert_eq!(vacated, 0);
+    }
+
+    #[test]
+    fn zvec_drain_wipes_as_it_goes_and_leaves_the_vec_safe_if_leaked() {
+        let mut z = ZVec::from(ZInlineVec::<u8, 3>::new());
+        z.push(1);
+        z.push(2);
+        z.push(3);
+
+        let mut drain = z.drain();
+        assert_eq!(drain.next(), Some(1));
+        let vacated = unsafe { drain.vec.0.as_ptr().read() };
+        assert_eq!(vacated, 0);
+
+        // Leak the rest of the iterator: the vector's length was already
+        // lowered to 0 by `drain()`, so this must not resurrect the
+        // remaining, not-yet-wiped elements.
+        core::mem::forget(drain);
+        assert_eq!(z.len(), 0);
+    }
+
     #[cfg(feature = "alloc")]
     #[test]
     fn zeroize_realloc() {
@@ -175,4 +697,46 @@ mod tests {
         let vals = unsafe { (stor.get_ptr().cast::<[u8; 3]>()).read() };
         assert_eq!(vals, [0, 0, 0]);
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn realloc_grow_wipes_old_block_before_it_can_be_freed() {
+        let mut old = ZReallocStorage::<u8>::try_with_capacity(2).unwrap();
+        unsafe {
+            old.get_mut_ptr().write(0xAA);
+            old.get_mut_ptr().add(1).write(0xBB);
+        }
+
+        // `try_grow` takes `&self`, so `old` is still alive (and its
+        // backing bytes still readable without UB) after this call --
+        // exactly what lets us check it was wiped in place rather than
+        // just freed holding secret bytes.
+        let new = old.try_grow::<usize>(Some(8)).unwrap();
+        assert!(new.capacity() >= 8);
+        let copied = unsafe { core::slice::from_raw_parts(new.get_ptr(), 2) };
+        assert_eq!(copied, [0xAA, 0xBB]);
+
+        let old_bytes = unsafe { core::slice::from_raw_parts(old.get_ptr(), 2) };
+        assert_eq!(old_bytes, [0, 0]);
+    }
+
+    #[cfg(feature = "io")]
+    #[test]
+    fn write_then_zcursor_read_leaves_the_buffer_wiped() {
+        let mut z = ZVec::from(ZInlineVec::<u8, 4>::new());
+        let written = embedded_io::Write::write(&mut z, &[1, 2, 3]).unwrap();
+        assert_eq!(written, 3);
+
+        let mut out = [0u8; 3];
+        {
+            let mut cursor = ZCursor::new(&mut z);
+            let read = embedded_io::Read::read(&mut cursor, &mut out).unwrap();
+            assert_eq!(read, 3);
+        }
+        assert_eq!(out, [1, 2, 3]);
+
+        assert_eq!(z.len(), 0);
+        let vacated = unsafe { core::slice::from_raw_parts(z.as_ptr(), 3) };
+        assert_eq!(vacated, [0, 0, 0]);
+    }
 }